@@ -23,6 +23,13 @@ public class User {
     public static final int MAX_AGE = 150;
     public static final String DEFAULT_ROLE = "USER";
 
+    // Text block
+    public static final String SUMMARY_TEMPLATE = """
+            User report
+              name:  %s
+              email: %s
+            """;
+
     public User(Long id, String name, String email, int age) {
         this.id = id;
         this.name = name;
@@ -58,6 +65,7 @@ public class User {
 /**
  * Generic repository interface
  */
+@SuppressWarnings({"unused", "rawtypes"})
 interface Repository<T, ID> {
     Optional<T> findById(ID id);
     List<T> findAll();
@@ -65,6 +73,14 @@ interface Repository<T, ID> {
     void delete(ID id);
 }
 
+/**
+ * Single-method lookup, usable as a lambda target
+ */
+@FunctionalInterface
+interface Finder<T, ID> {
+    Optional<T> findById(ID id);
+}
+
 /**
  * User repository implementation with in-memory storage
  */
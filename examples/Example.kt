@@ -226,6 +226,42 @@ fun demonstrateScopeFunctions() {
     }
 }
 
+// Soft keywords: value, by
+@JvmInline
+value class Email(val value: String)
+
+val defaultConfig by lazy { mapOf("version" to API_VERSION) }
+
+// Raw strings with templates
+fun formatReport(users: List<User>): String {
+    val lines = users.joinToString("\n") { "  - ${it.name} (${it.age})" }
+    return """
+        |Report for ${users.size} users:
+        |$lines
+    """.trimMargin()
+}
+
+// Labeled returns and breaks
+fun firstAdmin(groups: List<List<User>>): User? {
+    var found: User? = null
+    outer@ for (group in groups) {
+        for (user in group) {
+            if (user.isAdmin) {
+                found = user
+                break@outer
+            }
+        }
+    }
+    return found
+}
+
+fun printMinors(users: List<User>) {
+    users.forEach { user ->
+        if (user.isAdult) return@forEach
+        println("Minor: ${user.name}")
+    }
+}
+
 // Null safety demonstration
 fun demonstrateNullSafety() {
     val nullableString: String? = null
@@ -12,6 +12,23 @@
 #define MAX_NAME_LEN 50
 #define MAX_EMAIL_LEN 100
 #define API_VERSION "v1.0"
+#define FLAG_ADMIN 0x01
+#define FLAG_VERIFIED 0x02
+#define FILE_MODE 0644
+#define DEFAULT_RATIO 0.75f
+#define FIELD_SEPARATOR ','
+
+/* Multi-line macro with line continuations */
+#define LOG_ERROR(fmt, ...)                                  \
+    do {                                                     \
+        fprintf(stderr, "[error] " fmt "\n", ##__VA_ARGS__); \
+    } while (0)
+
+#ifdef DEBUG
+#define LOG_DEBUG(msg) printf("[debug] %s\n", msg)
+#else
+#define LOG_DEBUG(msg) ((void)0)
+#endif
 
 // Type definitions
 typedef struct User {
@@ -18,6 +18,14 @@ constexpr int MAX_USERS = 1000;
 constexpr double PI = 3.14159265359;
 const string API_VERSION = "v1.0";
 
+// Raw string literals (a custom delimiter allows )" inside the body)
+const string USAGE = R"(Usage: server --port <port>)";
+const string QUERY = R"sql(SELECT * FROM users WHERE note = "(none)")sql";
+
+// User-defined literal suffix
+constexpr unsigned long long operator""_kb(unsigned long long n) { return n * 1024; }
+constexpr auto MAX_UPLOAD = 512_kb;
+
 /**
  * User class with modern C++ features
  */
@@ -47,7 +55,7 @@ public:
   void setAge(int newAge) { age = newAge; }
 
   // Computed property
-  bool isAdult() const { return age >= 18; }
+  [[nodiscard]] bool isAdult() const { return age >= 18; }
 
   // Friend function for output
   friend ostream &operator<<(ostream &os, const User &user) {
@@ -7,6 +7,8 @@ using System.Linq;
 using System.Threading.Tasks;
 using Microsoft.AspNetCore.Mvc;
 
+#nullable enable
+
 namespace Example.Api
 {
     /// <summary>
@@ -20,9 +22,15 @@ namespace Example.Api
         public int Age { get; set; }
         public DateTime CreatedAt { get; set; }
         public UserRole Role { get; set; }
+        public string? Nickname { get; set; }
+
+        // Verbatim and interpolated verbatim strings
+        private const string DataDir = @"C:\data\users";
+        public string FilePath => $@"{DataDir}\{Id}.json";
 
-        // Computed property
+        #region Computed properties
         public bool IsAdult => Age >= 18;
+        #endregion
 
         public User(string name, string email, int age)
         {
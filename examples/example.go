@@ -1,6 +1,8 @@
 // Go Example - HTTP API Server with Goroutines
 // Testing: comments, strings, numbers, keywords, structs, interfaces, goroutines
 
+//go:build !windows
+
 package main
 
 import (
@@ -20,6 +22,21 @@ const (
 	Port       = ":8080"
 )
 
+// Role levels using iota
+const (
+	RoleGuest = iota
+	RoleUser
+	RoleAdmin
+)
+
+// Rune literals and raw strings
+const (
+	Separator = ','
+	Newline   = '\n'
+	Usage     = `Usage: server [flags]
+  -port string   listen address (default ":8080")`
+)
+
 // User represents a user entity
 type User struct {
 	ID        int       `json:"id"`
@@ -0,0 +1,123 @@
+{-# LANGUAGE LambdaCase #-}
+{-# LANGUAGE OverloadedStrings #-}
+-- Haskell Example - User Management with Type Classes
+-- Testing: comments, strings, chars, operators, records, type classes, pragmas
+
+module Main (main) where
+
+import Data.Char (isAlphaNum, toLower)
+import Data.List (sortOn)
+import qualified Data.Map.Strict as Map
+
+{- Block comments can nest:
+   {- this inner comment does not end the outer one -}
+   so this line is still a comment. -}
+
+-- Constants
+apiVersion :: String
+apiVersion = "v1.0"
+
+maxUsers :: Int
+maxUsers = 1000
+
+-- User role
+data UserRole = Guest | Member | Admin
+  deriving (Show, Eq, Ord, Enum, Bounded)
+
+-- User record
+data User = User
+  { userId :: Int
+  , userName :: String
+  , userEmail :: String
+  , userAge :: Int
+  , userRole :: UserRole
+  }
+  deriving (Show, Eq)
+
+-- Custom error type
+data UserError
+  = NotFound Int
+  | InvalidEmail String
+  | DuplicateEmail String
+  deriving (Show)
+
+-- Type class with a default method
+class Describable a where
+  describe :: a -> String
+  describe _ = "<opaque>"
+
+instance Describable User where
+  describe u = userName u ++ " <" ++ userEmail u ++ ">"
+
+-- Char literals vs. primes in identifiers
+separator :: Char
+separator = ','
+
+quote :: Char
+quote = '\''
+
+isAdult, isAdult' :: User -> Bool
+isAdult u = userAge u >= 18
+isAdult' = (>= 18) . userAge
+
+-- Multi-line string with a gap escape
+usage :: String
+usage = "Usage: users [command]\n\
+        \  list   List all users\n\
+        \  add    Add a user\n"
+
+-- User-defined operators
+infixl 4 <+>
+(<+>) :: Maybe Int -> Maybe Int -> Maybe Int
+a <+> b = (+) <$> a <*> b
+
+-- Repository as an immutable map
+type Repository = Map.Map Int User
+
+emptyRepository :: Repository
+emptyRepository = Map.empty
+
+validateEmail :: String -> Either UserError String
+validateEmail email
+  | '@' `elem` email, all validChar email = Right (map toLower email)
+  | otherwise = Left (InvalidEmail email)
+  where
+    validChar c = isAlphaNum c || c `elem` ("@.+-_" :: String)
+
+saveUser :: User -> Repository -> Either UserError Repository
+saveUser user repo = do
+  email <- validateEmail (userEmail user)
+  let duplicate = any ((== email) . userEmail) (Map.elems repo)
+  if duplicate
+    then Left (DuplicateEmail email)
+    else Right (Map.insert (userId user) user { userEmail = email } repo)
+
+findUser :: Int -> Repository -> Either UserError User
+findUser uid repo = maybe (Left (NotFound uid)) Right (Map.lookup uid repo)
+
+averageAge :: Repository -> Double
+averageAge repo
+  | Map.null repo = 0
+  | otherwise = fromIntegral (sum ages) / fromIntegral (length ages)
+  where
+    ages = map userAge (Map.elems repo)
+
+roleLabel :: UserRole -> String
+roleLabel = \case
+  Guest -> "guest"
+  Member -> "member"
+  Admin -> "admin"
+
+-- Main
+main :: IO ()
+main = do
+  let alice = User 1 "Alice Johnson" "Alice@Example.com" 28 Admin
+      bob = User 2 "Bob Smith" "bob@example.com" 17 Member
+  case saveUser alice emptyRepository >>= saveUser bob of
+    Left err -> putStrLn ("Error: " ++ show err)
+    Right repo -> do
+      mapM_ (putStrLn . describe) (sortOn userName (Map.elems repo))
+      putStrLn ("Adults: " ++ show (length (filter isAdult (Map.elems repo))))
+      putStrLn ("Average age: " ++ show (averageAge repo))
+      print (Just 1 <+> Just 2)
+      putStr usage
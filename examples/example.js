@@ -35,7 +35,9 @@ class User {
 const PORT = process.env.PORT || 3000;
 const API_VERSION = 'v1';
 const MAX_RETRIES = 5;
-const TIMEOUT_MS = 30000;
+const TIMEOUT_MS = 30_000;
+const MAX_SAFE_ID = 9_007_199_254_740_993n;
+const FLAGS = 0b1010_0101;
 const DEBUG = true;
 
 // Initialize Express app
@@ -93,6 +95,12 @@ const multiline = `
   string with ${API_VERSION}
 `;
 
+// Regular expressions (and division that looks like one)
+const EMAIL_PATTERN = /^[\w.+-]+@[a-z\d-]+(\.[a-z\d-]+)*\.[a-z]+$/i;
+const isValidEmail = (email) => EMAIL_PATTERN.test(email);
+const slug = 'Hello World'.replace(/\s+/g, '-').toLowerCase();
+const average = sum / numbers.length;
+
 // Start server
 const server = createServer(app);
 server.listen(PORT, () => {
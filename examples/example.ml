@@ -0,0 +1,112 @@
+(* OCaml Example - User Management with Modules and Variants
+   Testing: comments, strings, chars, variants, records, modules, labeled arguments *)
+
+(* Comments nest (* like this *), and a quote inside one is fine: don't
+   let the apostrophe in "don't" open a char literal. *)
+
+(* Constants *)
+let api_version = "v1.0"
+let max_users = 1_000
+let timeout_secs = 30.0
+
+(* User role *)
+type role = Guest | Member | Admin
+
+(* User record *)
+type user = {
+  id : int;
+  name : string;
+  email : string;
+  age : int;
+  role : role;
+}
+
+(* Errors as polymorphic variants *)
+type error = [ `Not_found of int | `Invalid_email of string | `Duplicate_email of string ]
+
+let role_to_string = function
+  | Guest -> "guest"
+  | Member -> "member"
+  | Admin -> "admin"
+
+let is_adult user = user.age >= 18
+
+(* Char literals vs. type variables *)
+let separator = ','
+let newline = '\n'
+let identity : 'a -> 'a = fun x -> x
+
+(* Quoted string literals need no escaping *)
+let usage = {|Usage: users [command]
+  list   List all users
+  add    Add a user ("quotes" and \backslashes\ are literal)|}
+
+let query = {sql|SELECT * FROM users WHERE name = 'O''Brien'|sql}
+
+(* Repository module *)
+module Repository : sig
+  type t
+
+  val empty : t
+  val save : user -> t -> (t, [> error ]) result
+  val find : int -> t -> (user, [> error ]) result
+  val all : t -> user list
+end = struct
+  module IntMap = Map.Make (Int)
+
+  type t = user IntMap.t
+
+  let empty = IntMap.empty
+
+  let validate_email email =
+    if String.contains email '@' then Ok (String.lowercase_ascii email)
+    else Error (`Invalid_email email)
+
+  let save user repo =
+    match validate_email user.email with
+    | Error e -> Error e
+    | Ok email ->
+        let duplicate = IntMap.exists (fun _ u -> u.email = email) repo in
+        if duplicate then Error (`Duplicate_email email)
+        else Ok (IntMap.add user.id { user with email } repo)
+
+  let find id repo =
+    match IntMap.find_opt id repo with
+    | Some user -> Ok user
+    | None -> Error (`Not_found id)
+
+  let all repo = List.map snd (IntMap.bindings repo)
+end
+
+(* Labeled and optional arguments *)
+let create_user ?(role = Member) ~name ~email ~age id = { id; name; email; age; role }
+
+let average_age users =
+  match users with
+  | [] -> 0.0
+  | _ ->
+      let total = List.fold_left (fun acc u -> acc + u.age) 0 users in
+      float_of_int total /. float_of_int (List.length users)
+
+let describe_error : error -> string = function
+  | `Not_found id -> Printf.sprintf "No user with id %d" id
+  | `Invalid_email email | `Duplicate_email email ->
+      Printf.sprintf "Problem with email: %s" email
+
+(* Main *)
+let () =
+  let alice = create_user ~role:Admin ~name:"Alice Johnson" ~email:"Alice@Example.com" ~age:28 1 in
+  let bob = create_user ~name:"Bob Smith" ~email:"bob@example.com" ~age:17 2 in
+  let result =
+    Result.bind (Repository.save alice Repository.empty) (Repository.save bob)
+  in
+  match result with
+  | Error err -> print_endline ("Error: " ^ describe_error err)
+  | Ok repo ->
+      let users = Repository.all repo in
+      List.iter
+        (fun u -> Printf.printf "%s <%s> (%s)\n" u.name u.email (role_to_string u.role))
+        users;
+      Printf.printf "Adults: %d\n" (List.length (List.filter is_adult users));
+      Printf.printf "Average age: %.2f\n" (average_age users);
+      print_string usage
@@ -8,6 +8,7 @@ declare(strict_types=1);
 
 namespace App\Example;
 
+use Attribute;
 use DateTime;
 use Exception;
 
@@ -16,6 +17,29 @@ const API_VERSION = 'v1';
 const MAX_USERS = 1000;
 const TIMEOUT = 30;
 
+// Heredoc (interpolated) and nowdoc (literal)
+$appName = 'Example';
+$startedAt = new DateTime();
+$banner = <<<EOT
+    Welcome to $appName
+    Started at {$startedAt->format('Y-m-d H:i')}
+    EOT;
+
+$template = <<<'SQL'
+    SELECT * FROM users WHERE name = '$notInterpolated'
+    SQL;
+
+/**
+ * Attribute for tagging cached methods
+ */
+#[Attribute(Attribute::TARGET_METHOD)]
+final class Cached
+{
+    public function __construct(public int $ttl = 60)
+    {
+    }
+}
+
 /**
  * User model class
  */
@@ -189,6 +213,7 @@ class UserService
         return $this->repository->save($user);
     }
 
+    #[Cached(ttl: 300)]
     public function getAdultUsers(): array
     {
         $users = $this->repository->findAll();
@@ -338,3 +363,10 @@ try {
 
 // Demonstrate arrays
 demonstrateArrays();
+?>
+<!-- Mixed HTML/PHP template -->
+<ul class="users">
+<?php foreach ($service->getAdultUsers() as $user): ?>
+    <li title="<?= htmlspecialchars($user->getEmail()) ?>"><?= htmlspecialchars($user->getName()) ?></li>
+<?php endforeach; ?>
+</ul>
@@ -18,6 +18,8 @@ logger = logging.getLogger(__name__)
 API_VERSION = "v1"
 MAX_CONNECTIONS = 100
 TIMEOUT = 30.0
+MAX_UPLOAD_BYTES = 10_000_000
+FILE_MODE = 0o644
 DEBUG = True
 
 
@@ -113,6 +115,19 @@ with multiple lines
 and {API_VERSION} interpolation
 """
 
+# Structural pattern matching (match/case are soft keywords)
+def describe(user: Optional[User]) -> str:
+    match user:
+        case None:
+            return "anonymous"
+        case User(age=age) if age < 18:
+            return "minor"
+        case _:
+            return "adult"
+
+
+match = "still a valid variable name"
+
 # Context manager
 class DatabaseConnection:
     def __enter__(self):
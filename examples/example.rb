@@ -9,6 +9,20 @@ require 'json'
 API_VERSION = 'v1.0'
 MAX_USERS = 1000
 TIMEOUT = 30
+ROLES = %i[guest user admin].freeze
+RESERVED_NAMES = %w[root admin system].freeze
+SLUG_PATTERN = %r{\A[a-z0-9]+(?:-[a-z0-9]+)*\z}
+
+# Heredocs (squiggly with interpolation, and quoted without)
+USAGE = <<~USAGE
+  Usage: users [command]
+    list   List all users (API #{API_VERSION})
+    add    Add a user
+USAGE
+
+SQL_TEMPLATE = <<-'SQL'
+  SELECT * FROM users WHERE name = '#{not_interpolated}'
+SQL
 
 # User class with ActiveRecord-style methods
 class User
@@ -0,0 +1,177 @@
+// Swift Example - User Management with Protocols and Property Wrappers
+// Testing: comments, strings, numbers, keywords, structs, enums, protocols, attributes
+
+import Foundation
+
+// Constants
+let apiVersion = "v1.0"
+let maxUsers = 1_000
+let timeoutSeconds: Double = 30.0
+
+// User role enum
+enum UserRole: String, CaseIterable {
+    case guest
+    case user
+    case admin
+
+    var isPrivileged: Bool {
+        self == .admin
+    }
+}
+
+// Property wrapper
+@propertyWrapper
+struct Clamped<Value: Comparable> {
+    private var value: Value
+    private let range: ClosedRange<Value>
+
+    init(wrappedValue: Value, _ range: ClosedRange<Value>) {
+        self.range = range
+        self.value = min(max(wrappedValue, range.lowerBound), range.upperBound)
+    }
+
+    var wrappedValue: Value {
+        get { value }
+        set { value = min(max(newValue, range.lowerBound), range.upperBound) }
+    }
+}
+
+// User struct
+struct User: Identifiable, CustomStringConvertible {
+    let id: Int
+    var name: String
+    var email: String
+    @Clamped(0...150) var age: Int = 0
+    var role: UserRole = .user
+    let createdAt = Date()
+
+    var isAdult: Bool {
+        age >= 18
+    }
+
+    // String interpolation
+    var description: String {
+        "User(id: \(id), name: \"\(name)\", role: \(role.rawValue))"
+    }
+}
+
+// Custom error type
+enum UserError: Error {
+    case notFound(id: Int)
+    case invalidEmail(String)
+    case duplicateEmail(String)
+}
+
+// Repository protocol
+protocol Repository {
+    associatedtype Entity: Identifiable
+
+    func find(id: Entity.ID) -> Entity?
+    func findAll() -> [Entity]
+    mutating func save(_ entity: Entity) throws -> Entity
+    mutating func delete(id: Entity.ID) -> Bool
+}
+
+// User repository implementation
+struct UserRepository: Repository {
+    private var users: [Int: User] = [:]
+    private var nextId = 1
+
+    func find(id: Int) -> User? {
+        users[id]
+    }
+
+    func findAll() -> [User] {
+        users.values.sorted { $0.id < $1.id }
+    }
+
+    func findByEmail(_ email: String) -> User? {
+        users.values.first { $0.email == email }
+    }
+
+    mutating func save(_ user: User) throws -> User {
+        guard user.email.contains("@") else {
+            throw UserError.invalidEmail(user.email)
+        }
+        if let existing = findByEmail(user.email), existing.id != user.id {
+            throw UserError.duplicateEmail(user.email)
+        }
+
+        var saved = user
+        if user.id == 0 {
+            saved = User(id: nextId, name: user.name, email: user.email, age: user.age, role: user.role)
+            nextId += 1
+        }
+        users[saved.id] = saved
+        return saved
+    }
+
+    @discardableResult
+    mutating func delete(id: Int) -> Bool {
+        users.removeValue(forKey: id) != nil
+    }
+}
+
+// User service with business logic
+final class UserService {
+    private var repository = UserRepository()
+
+    func createUser(name: String, email: String, age: Int, role: UserRole = .user) throws -> User {
+        let user = User(id: 0, name: name, email: email, age: age, role: role)
+        return try repository.save(user)
+    }
+
+    func adultUsers() -> [User] {
+        repository.findAll().filter(\.isAdult)
+    }
+
+    func averageAge() -> Double {
+        let users = repository.findAll()
+        guard !users.isEmpty else { return 0 }
+        return Double(users.map(\.age).reduce(0, +)) / Double(users.count)
+    }
+
+    @available(*, deprecated, message: "Use adultUsers() instead")
+    func getAdults() -> [User] {
+        adultUsers()
+    }
+}
+
+// Multi-line strings
+let usage = """
+    Usage: users <command>
+      list    List all users
+      add     Add a user (version \(apiVersion))
+    """
+
+// Backtick-escaped identifiers
+let `default` = UserRole.guest
+let `class` = "reserved word as a name"
+
+// Pattern matching
+func describe(_ error: UserError) -> String {
+    switch error {
+    case .notFound(let id):
+        return "No user with id \(id)"
+    case .invalidEmail(let email), .duplicateEmail(let email):
+        return "Problem with email: \(email)"
+    }
+}
+
+// Async function
+func fetchUser(id: Int) async throws -> User {
+    try await Task.sleep(nanoseconds: 1_000_000)
+    return User(id: id, name: "Async User", email: "async@example.com", age: 30)
+}
+
+// Main
+let service = UserService()
+do {
+    let alice = try service.createUser(name: "Alice Johnson", email: "alice@example.com", age: 28, role: .admin)
+    let bob = try service.createUser(name: "Bob Smith", email: "bob@example.com", age: 17)
+    print("Created \(alice) and \(bob)")
+    print("Adults: \(service.adultUsers().count)")
+    print("Average age: \(service.averageAge())")
+} catch {
+    print("Error: \(error)")
+}
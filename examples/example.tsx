@@ -1,4 +1,4 @@
-// TypeScript Example - React Component with Hooks and Context
+// TypeScript (TSX) Example - React Component with Hooks and Context
 // Testing: types, interfaces, generics, decorators, JSX
 
 import React, { useState, useEffect, useContext, createContext } from 'react';
@@ -15,6 +15,12 @@ interface User {
 
 type UserRole = 'admin' | 'user' | 'guest';
 
+enum Permission {
+  Read = 1 << 0,
+  Write = 1 << 1,
+  Admin = Read | Write,
+}
+
 interface ApiResponse<T> {
   data: T;
   status: number;
@@ -38,10 +44,22 @@ const API_ENDPOINT = 'https://api.example.com';
 const MAX_RETRIES = 3;
 const TIMEOUT_MS = 5000;
 
-// Class with decorators (conceptual)
+// Method decorator
+function logged<This, Args extends unknown[], Return>(
+  target: (this: This, ...args: Args) => Return,
+  context: ClassMethodDecoratorContext<This, (this: This, ...args: Args) => Return>,
+): (this: This, ...args: Args) => Return {
+  return function (this: This, ...args: Args): Return {
+    console.log(`Calling ${String(context.name)}`);
+    return target.apply(this, args);
+  };
+}
+
+// Class with decorators
 class UserService {
   private users: User[] = [];
 
+  @logged
   async fetchUser(id: number): Promise<Nullable<User>> {
     try {
       const response = await fetch(`${API_ENDPOINT}/users/${id}`);
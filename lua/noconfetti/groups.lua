@@ -47,11 +47,15 @@ M.setup = function(palette)
 	groups.Delimiter = { fg = palette.punctuation }
 	groups.PreProc = { fg = palette.orange_fg, bg = palette.orange_bg }
 	groups.Error = { fg = palette.error }
+	groups.Todo = { fg = palette.accent, bold = true }
 
 	-- Treesitter
 	groups["@comment"] = { link = "Comment" }
 	groups["@comment.error"] = { fg = palette.error, bold = true }
 	groups["@comment.warning"] = { fg = palette.warning, bold = true }
+	groups["@comment.todo"] = { link = "Todo" }
+	groups["@comment.note"] = { fg = palette.info, bold = true }
+	groups["@nospell.comment"] = {} -- Keep @nospell from overriding comment tags
 	groups["@string.documentation"] = { link = "Comment" }
 	groups["@keyword.jsdoc"] = { link = "Comment" }
 	groups["@spell"] = { link = "Comment" }
@@ -59,6 +63,7 @@ M.setup = function(palette)
 
 	groups["@string"] = { link = "String" }
 	groups["@string.special.symbol"] = { fg = palette.neutral }
+	groups["@string.regexp"] = { link = "String" }
 
 	groups["@constant"] = { link = "Constant" }
 	groups["@constant.builtin"] = { fg = palette.neutral }
@@ -91,6 +96,9 @@ M.setup = function(palette)
 	groups["@tag.attribute"] = { fg = palette.neutral }
 	groups["@tag.delimiter"] = { fg = palette.punctuation }
 
+	groups["@attribute"] = { fg = palette.punctuation }
+	groups["@attribute.builtin"] = { link = "@attribute" }
+
 	-- Language-specific: Go
 	groups["@type.definition.go"] = { fg = palette.purple_fg, bg = palette.purple_bg }
 	groups["@variable.parameter.go"] = { fg = palette.blue_fg, bg = palette.blue_bg }
@@ -169,11 +177,12 @@ M.setup = function(palette)
 	groups["@lsp.typemod.parameter.functionScope.c"] = { fg = palette.blue_fg, bg = palette.blue_bg }
 
 	-- Language-specific: Python
+	groups["@keyword.directive.python"] = { fg = palette.punctuation, bg = palette.bg } -- Shebang
+	groups["@nospell.python"] = {} -- Disable spell highlighting override for the shebang
 	groups["@type.python"] = { fg = palette.neutral }
 	groups["@type.builtin.python"] = { fg = palette.neutral }
 	groups["@lsp.typemod.variable.readonly.python"] = { fg = palette.teal_fg, bg = palette.teal_bg }
 	groups["@lsp.typemod.class.declaration.python"] = { fg = palette.purple_fg, bg = palette.purple_bg }
-	groups["@lsp.type.decorator.python"] = { fg = palette.purple_fg, bg = palette.purple_bg }
 	groups["@lsp.type.parameter.python"] = { fg = palette.blue_fg, bg = palette.blue_bg }
 	groups["@lsp.typemod.parameter.declaration.python"] = { fg = palette.blue_fg, bg = palette.blue_bg }
 	groups["@lsp.typemod.parameter.parameter.python"] = { fg = palette.blue_fg, bg = palette.blue_bg }
@@ -185,12 +194,26 @@ M.setup = function(palette)
 	groups["@type.rust"] = { fg = palette.neutral }
 	groups["@type.builtin.rust"] = { fg = palette.neutral }
 	groups["@lsp.type.method.rust"] = { fg = palette.neutral }
+	groups["@lsp.type.attribute.rust"] = { link = "@attribute" }
+	groups["@lsp.type.attributeBracket.rust"] = { link = "@attribute" }
+	groups["@lsp.type.builtinAttribute.rust"] = { link = "@attribute" }
+	groups["@lsp.type.derive.rust"] = { link = "@attribute" }
+	groups["@lsp.type.deriveHelper.rust"] = { link = "@attribute" }
 
 	-- Language-specific: Ruby
+	groups["@keyword.directive.ruby"] = { fg = palette.punctuation, bg = palette.bg } -- Shebang
+	groups["@nospell.ruby"] = {} -- Disable spell highlighting override for the shebang
 	groups["@variable.parameter.ruby"] = { fg = palette.blue_fg, bg = palette.blue_bg }
 	groups["@lsp.typemod.class.declaration.ruby"] = { fg = palette.purple_fg, bg = palette.purple_bg }
 	groups["@lsp.typemod.namespace.declaration.ruby"] = { fg = palette.purple_fg, bg = palette.purple_bg }
 
+	-- Language-specific: Shell/Bash
+	groups["@keyword.directive.bash"] = { fg = palette.punctuation, bg = palette.bg } -- Shebang
+	groups["@nospell.bash"] = {} -- Disable spell highlighting override for the shebang
+
+	-- Language-specific: Haskell
+	groups["@keyword.directive.haskell"] = { link = "@attribute" } -- {-# LANGUAGE ... #-} pragmas
+
 	-- LSP
 	groups["@lsp"] = { fg = palette.neutral }
 	groups["@lsp.type.function"] = { fg = palette.neutral }
@@ -198,6 +221,8 @@ M.setup = function(palette)
 	groups["@lsp.typemod.method.declaration"] = { fg = palette.purple_fg, bg = palette.purple_bg }
 	groups["@lsp.typemod.variable.declaration"] = { fg = palette.blue_fg, bg = palette.blue_bg }
 	groups["@lsp.typemod.parameter.declaration"] = { fg = palette.blue_fg, bg = palette.blue_bg }
+	groups["@lsp.type.annotation"] = { link = "@attribute" }
+	groups["@lsp.type.decorator"] = { link = "@attribute" }
 
 	-- Diagnostics
 	groups.DiagnosticUnnecessary = { fg = palette.punctuation }